@@ -67,6 +67,30 @@ pub struct CliArgs {
     #[arg(short = 'w', long)]
     pub terse: bool,
 
+    /// Align files on byte insertions/deletions before diffing, instead of comparing by absolute offset
+    #[arg(short = 'A', long)]
+    pub align: bool,
+
+    /// Numeric radix for hexdump byte/offset output: hex, HEX, octal, binary, or decimal
+    #[arg(short = 'f', long, default_value = "HEX")]
+    pub format: String,
+
+    /// Export the input data as a ready-to-paste source array instead of a hex dump (c, rust, python, hexstring)
+    #[arg(long, value_name = "LANG")]
+    pub array: Option<String>,
+
+    /// Control when colored output is used: never, always, or auto (respects NO_COLOR)
+    #[arg(long, value_name = "WHEN", default_value = "auto")]
+    pub color: String,
+
+    /// Skip this many bytes before dumping (accepts 0x-prefixed hex and K/M/G suffixes)
+    #[arg(long, value_name = "N")]
+    pub skip: Option<String>,
+
+    /// Only dump this many bytes, starting at --skip (accepts 0x-prefixed hex and K/M/G suffixes)
+    #[arg(long, value_name = "N")]
+    pub length: Option<String>,
+
     /// Set file block size (hexdump line size)
     #[arg(short = 'K', long, default_value_t = 16)]
     pub block: usize,
@@ -29,6 +29,220 @@ impl ByteClass {
     }
 }
 
+/// Numeric radix used to render byte cells and the offset column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberFormat {
+    /// Lowercase hex, 2 chars per byte (`4a`).
+    Hex,
+    /// Uppercase hex, 2 chars per byte (`4A`). The historical default.
+    #[default]
+    HexUpper,
+    /// Octal, 3 chars per byte (`112`).
+    Octal,
+    /// Binary, 8 chars per byte (`01001010`).
+    Binary,
+    /// Decimal, 3 chars per byte (`074`).
+    Decimal,
+}
+
+impl NumberFormat {
+    /// Parse the `--format`/`-f` CLI value, mirroring the radix names used by `hx`.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "hex" => Ok(NumberFormat::Hex),
+            "HEX" => Ok(NumberFormat::HexUpper),
+            "octal" => Ok(NumberFormat::Octal),
+            "binary" => Ok(NumberFormat::Binary),
+            "decimal" => Ok(NumberFormat::Decimal),
+            other => Err(format!(
+                "Unknown format '{other}', expected one of: hex, HEX, octal, binary, decimal"
+            )),
+        }
+    }
+
+    /// Width, in characters, of one rendered byte cell in this radix.
+    pub fn cell_width(self) -> usize {
+        match self {
+            NumberFormat::Hex | NumberFormat::HexUpper => 2,
+            NumberFormat::Octal | NumberFormat::Decimal => 3,
+            NumberFormat::Binary => 8,
+        }
+    }
+
+    /// Render a single byte as a fixed-width string in this radix.
+    pub fn render_byte(self, b: u8) -> String {
+        let width = self.cell_width();
+        match self {
+            NumberFormat::Hex => format!("{b:0width$x}"),
+            NumberFormat::HexUpper => format!("{b:0width$X}"),
+            NumberFormat::Octal => format!("{b:0width$o}"),
+            NumberFormat::Binary => format!("{b:0width$b}"),
+            NumberFormat::Decimal => format!("{b:0width$}"),
+        }
+    }
+
+    /// Gap/EOF placeholder for a byte cell, same width as a rendered byte.
+    pub fn gap_placeholder(self) -> String {
+        "X".repeat(self.cell_width())
+    }
+
+    /// Render a file offset in this radix (the `OFFSET` column).
+    pub fn render_offset(self, offset: usize) -> String {
+        match self {
+            NumberFormat::Hex => format!("0x{offset:08x}"),
+            NumberFormat::HexUpper => format!("0x{offset:08X}"),
+            NumberFormat::Octal => format!("0{offset:011o}"),
+            NumberFormat::Binary => format!("0b{offset:032b}"),
+            NumberFormat::Decimal => format!("{offset:010}"),
+        }
+    }
+}
+
+/// When to colorize output, mirroring `ripgrep`'s `--color` convention.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorMode {
+    /// Never colorize, regardless of terminal detection.
+    Never,
+    /// Always colorize, even when stdout isn't a terminal (e.g. piping into `less -R`).
+    Always,
+    /// Colorize only when stdout is a terminal, unless `NO_COLOR` is set and non-empty.
+    #[default]
+    Auto,
+}
+
+impl ColorMode {
+    /// Parse the `--color` CLI value.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "never" => Ok(ColorMode::Never),
+            "always" => Ok(ColorMode::Always),
+            "auto" => Ok(ColorMode::Auto),
+            other => Err(format!(
+                "Unknown color mode '{other}', expected one of: never, always, auto"
+            )),
+        }
+    }
+}
+
+/// Target language for `--array` source-code export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayLang {
+    /// `unsigned char name[] = { 0x41, ... };`
+    C,
+    /// `let name: [u8; N] = [0x41, ...];`
+    Rust,
+    /// `name = bytes([0x41, ...])`
+    Python,
+    /// A bare concatenated hex string: `name = 4142...`
+    HexString,
+}
+
+impl ArrayLang {
+    /// Parse the `--array` CLI value.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "c" => Ok(ArrayLang::C),
+            "rust" => Ok(ArrayLang::Rust),
+            "python" => Ok(ArrayLang::Python),
+            "hexstring" => Ok(ArrayLang::HexString),
+            other => Err(format!(
+                "Unknown array language '{other}', expected one of: c, rust, python, hexstring"
+            )),
+        }
+    }
+}
+
+/// Derive a valid-looking array/variable name from a file name (used by `--array`).
+pub fn array_name(file_name: &str) -> String {
+    let stem = std::path::Path::new(file_name)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("data");
+
+    let mut name: String = stem
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    name
+}
+
+/// Render `data` as a ready-to-paste source array in the given language.
+pub fn render_array(name: &str, data: &[u8], lang: ArrayLang) -> String {
+    match lang {
+        ArrayLang::C => {
+            let bytes = data
+                .iter()
+                .map(|b| format!("0x{b:02x}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("unsigned char {name}[] = {{ {bytes} }};\n")
+        }
+        ArrayLang::Rust => {
+            let bytes = data
+                .iter()
+                .map(|b| format!("0x{b:02x}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("let {name}: [u8; {}] = [{bytes}];\n", data.len())
+        }
+        ArrayLang::Python => {
+            let bytes = data
+                .iter()
+                .map(|b| format!("0x{b:02x}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{name} = bytes([{bytes}])\n")
+        }
+        ArrayLang::HexString => {
+            let hex: String = data.iter().map(|b| format!("{b:02x}")).collect();
+            format!("{name} = {hex}\n")
+        }
+    }
+}
+
+/// Parse a byte count for `--skip`/`--length`, accepting `0x`-prefixed hex and
+/// `K`/`M`/`G` (binary, 1024-based) suffixes, e.g. `0x1000`, `4K`, `16M`.
+pub fn parse_size(s: &str) -> Result<usize, String> {
+    let trimmed = s.trim();
+
+    if let Some(hex) = trimmed.strip_prefix("0x").or_else(|| trimmed.strip_prefix("0X")) {
+        return usize::from_str_radix(hex, 16)
+            .map_err(|e| format!("Invalid hex size '{s}': {e}"));
+    }
+
+    let (digits, multiplier) = match trimmed.chars().last() {
+        Some(c) if c.eq_ignore_ascii_case(&'k') => (&trimmed[..trimmed.len() - 1], 1024),
+        Some(c) if c.eq_ignore_ascii_case(&'m') => (&trimmed[..trimmed.len() - 1], 1024 * 1024),
+        Some(c) if c.eq_ignore_ascii_case(&'g') => {
+            (&trimmed[..trimmed.len() - 1], 1024 * 1024 * 1024)
+        }
+        _ => (trimmed, 1),
+    };
+
+    let n = digits
+        .trim()
+        .parse::<usize>()
+        .map_err(|e| format!("Invalid size '{s}': {e}"))?;
+
+    n.checked_mul(multiplier)
+        .ok_or_else(|| format!("Invalid size '{s}': value is too large"))
+}
+
+/// Slice `data` down to the `--skip`/`--length` window (used by `--array` export).
+fn windowed_slice(data: &[u8], skip: usize, length: Option<usize>) -> &[u8] {
+    let start = skip.min(data.len());
+    let end = match length {
+        Some(length) => data.len().min(start.saturating_add(length)),
+        None => data.len(),
+    };
+    &data[start..end]
+}
+
 /// Options for controlling hexdump/diff output.
 #[derive(Debug, Clone)]
 pub struct HexdiffOptions {
@@ -44,6 +258,20 @@ pub struct HexdiffOptions {
     pub terse: bool,
     /// Collapse repeated identical lines (classic hexdump `*` style).
     pub collapse_repeats: bool,
+    /// Align files with a Myers diff before classification, so that an inserted or
+    /// deleted byte doesn't turn every subsequent line red.
+    pub align: bool,
+    /// Numeric radix for byte cells and the offset column.
+    pub format: NumberFormat,
+    /// When set, export the input(s) as source arrays instead of rendering a hex dump.
+    pub array: Option<ArrayLang>,
+    /// When to colorize output.
+    pub color: ColorMode,
+    /// Number of bytes to skip before the first displayed line (rounded down to a
+    /// block boundary).
+    pub skip: usize,
+    /// Number of bytes to display, starting at `skip`. `None` means "to the end".
+    pub length: Option<usize>,
 }
 
 impl Default for HexdiffOptions {
@@ -55,6 +283,12 @@ impl Default for HexdiffOptions {
             show_blue: true,
             terse: false,
             collapse_repeats: false,
+            align: false,
+            format: NumberFormat::default(),
+            array: None,
+            color: ColorMode::default(),
+            skip: 0,
+            length: None,
         }
     }
 }
@@ -145,12 +379,12 @@ fn is_printable_ascii(b: u8) -> bool {
     (0x21..=0x7E).contains(&b)
 }
 
-fn render_header(file_names: &[String], block: usize, terse: bool) -> String {
+fn render_header(file_names: &[String], block: usize, terse: bool, format: NumberFormat) -> String {
     let mut out = String::new();
     out.push_str("OFFSET      ");
 
-    // Match legacy width: (block * 4) + 2
-    let header_width = (block * 4) + 2;
+    // Match legacy width: block * (cell_width + 1) [hex column + space] + block + 2 [ascii column + bars]
+    let header_width = block * (format.cell_width() + 2) + 2;
     let count = if terse { 1 } else { file_names.len() };
     for i in 0..count {
         let name = &file_names[i];
@@ -165,26 +399,39 @@ fn render_line(
     files: &[(String, Vec<u8>)],
     block: usize,
     terse: bool,
+    format: NumberFormat,
 ) -> RenderedLine {
     let file_count = files.len();
-
-    // Collect per-position class (global) and per-file values
-    let mut classes: Vec<ByteClass> = Vec::with_capacity(block);
     let mut values: Vec<Vec<Option<u8>>> = vec![Vec::with_capacity(block); file_count];
 
     for i in 0..block {
         let absolute = offset + i;
-        let mut at_pos: Vec<Option<u8>> = Vec::with_capacity(file_count);
-        for (_name, data) in files.iter() {
-            at_pos.push(data.get(absolute).copied());
+        for (fidx, (_name, data)) in files.iter().enumerate() {
+            values[fidx].push(data.get(absolute).copied());
         }
+    }
 
-        let class = classify_position(&at_pos);
-        classes.push(class);
+    render_line_from_values(offset, &values, block, terse, format)
+}
 
-        for (fidx, v) in at_pos.into_iter().enumerate() {
-            values[fidx].push(v);
-        }
+/// Render a line from already-gathered per-file byte values.
+///
+/// `values[fidx][i]` is the byte (or gap, if `None`) that file `fidx` contributes
+/// to column `i` of this line. Used both for the normal offset-indexed path and for
+/// the Myers-aligned path, which pre-computes values that include gap columns.
+fn render_line_from_values(
+    offset: usize,
+    values: &[Vec<Option<u8>>],
+    block: usize,
+    terse: bool,
+    format: NumberFormat,
+) -> RenderedLine {
+    let file_count = values.len();
+
+    let mut classes: Vec<ByteClass> = Vec::with_capacity(block);
+    for i in 0..block {
+        let at_pos: Vec<Option<u8>> = values.iter().map(|v| v[i]).collect();
+        classes.push(classify_position(&at_pos));
     }
 
     let mut has_red = false;
@@ -201,9 +448,9 @@ fn render_line(
     let mut raw = String::new();
     let mut display = String::new();
 
-    // Offset format: 0x%.8X (legacy)
-    raw.push_str(&format!("0x{offset:08X}    "));
-    display.push_str(&format!("0x{offset:08X}    "));
+    let offset_str = format.render_offset(offset);
+    raw.push_str(&format!("{offset_str}    "));
+    display.push_str(&format!("{offset_str}    "));
 
     let count = if terse { 1 } else { file_count };
     for fidx in 0..count {
@@ -218,9 +465,9 @@ fn render_line(
             let class = classes[i];
 
             let (hex2, asc1) = match v {
-                None => ("XX".to_string(), ".".to_string()),
+                None => (format.gap_placeholder(), ".".to_string()),
                 Some(b) => {
-                    let hex2 = format!("{b:02X}");
+                    let hex2 = format.render_byte(b);
                     let asc1 = if is_printable_ascii(b) {
                         (b as char).to_string()
                     } else {
@@ -261,6 +508,284 @@ fn should_show_line(line: &RenderedLine, opts: &HexdiffOptions) -> bool {
     should_display_flags((line.has_red, line.has_green, line.has_blue), opts)
 }
 
+/// A single edit operation in a Myers diff script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EditOp {
+    /// Both sequences contribute the same byte at this step.
+    Equal,
+    /// Only `a` contributes a byte; `b` has a gap here.
+    Delete,
+    /// Only `b` contributes a byte; `a` has a gap here.
+    Insert,
+}
+
+/// Myers alignment is O(ND) in the edit distance `D`, not the input size; past this
+/// many steps without a match, bail out rather than let a pathological pair of
+/// inputs run unbounded.
+const MAX_EDIT_DISTANCE: isize = 4096;
+
+/// Compute the shortest edit script turning byte sequence `a` into `b`, using Myers'
+/// divide-and-conquer refinement: recursively split both sequences at a "middle
+/// snake" found by searching forward from the start and backward from the end at
+/// the same time, until the two searches meet. This keeps working memory at O(N+M).
+fn myers_diff(a: &[u8], b: &[u8]) -> Result<Vec<EditOp>, String> {
+    let mut ops = Vec::new();
+    diff_recursive(a, b, &mut ops)?;
+    Ok(ops)
+}
+
+/// Recursively diff `a` against `b`, appending the edit script to `ops` in order.
+fn diff_recursive(a: &[u8], b: &[u8], ops: &mut Vec<EditOp>) -> Result<(), String> {
+    // Trim a common prefix/suffix first: it's free (an `Equal` run) and shrinking
+    // the subproblem keeps the middle-snake search below cheaper.
+    let mut prefix = 0usize;
+    while prefix < a.len() && prefix < b.len() && a[prefix] == b[prefix] {
+        prefix += 1;
+    }
+    let mut suffix = 0usize;
+    while suffix < a.len() - prefix
+        && suffix < b.len() - prefix
+        && a[a.len() - 1 - suffix] == b[b.len() - 1 - suffix]
+    {
+        suffix += 1;
+    }
+
+    ops.extend(std::iter::repeat_n(EditOp::Equal, prefix));
+
+    let a_mid = &a[prefix..a.len() - suffix];
+    let b_mid = &b[prefix..b.len() - suffix];
+
+    if a_mid.is_empty() {
+        ops.extend(std::iter::repeat_n(EditOp::Insert, b_mid.len()));
+    } else if b_mid.is_empty() {
+        ops.extend(std::iter::repeat_n(EditOp::Delete, a_mid.len()));
+    } else {
+        let (x, y) = middle_snake(a_mid, b_mid)?;
+        diff_recursive(&a_mid[..x], &b_mid[..y], ops)?;
+        diff_recursive(&a_mid[x..], &b_mid[y..], ops)?;
+    }
+
+    ops.extend(std::iter::repeat_n(EditOp::Equal, suffix));
+    Ok(())
+}
+
+/// Find a point `(x, y)` that a shortest edit path from `(0, 0)` to `(a.len(),
+/// b.len())` passes through, using Myers' linear-space refinement: search forward
+/// from `(0, 0)` and backward from `(a.len(), b.len())` on alternating steps, each
+/// keeping only the current diagonal ("V array") rather than a snapshot per step,
+/// until the two searches overlap on the same diagonal.
+fn middle_snake(a: &[u8], b: &[u8]) -> Result<(usize, usize), String> {
+    let n = a.len() as isize;
+    let m = b.len() as isize;
+    let delta = n - m;
+    let odd_delta = delta % 2 != 0;
+
+    // Each step `d` extends both searches by one edit, so the two meet at
+    // d = ceil(D / 2); capping `d` there bounds the full edit distance `D`.
+    let max_d = ((n + m + 1) / 2 + 1).min((MAX_EDIT_DISTANCE + 1) / 2);
+    let size = (2 * max_d + 1) as usize;
+    let offset = max_d as usize;
+    let mut vf = vec![0isize; size];
+    let mut vb = vec![0isize; size];
+
+    for d in 0..=max_d {
+        // Forward search, one step further from (0, 0).
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset as isize) as usize;
+            let mut x = if k == -d || (k != d && vf[idx - 1] < vf[idx + 1]) {
+                vf[idx + 1]
+            } else {
+                vf[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            vf[idx] = x;
+
+            if odd_delta {
+                // A backward diagonal `c` corresponds to forward diagonal `delta - c`
+                // (the backward search measures distance from the end, which flips
+                // the diagonal through delta), so the backward diagonal matching this
+                // forward `k` is `delta - k`.
+                let kb = delta - k;
+                if kb > -d && kb < d {
+                    let idxb = (kb + offset as isize) as usize;
+                    if vb[idxb] + x >= n {
+                        return Ok((x as usize, y as usize));
+                    }
+                }
+            }
+
+            k += 2;
+        }
+
+        // Backward search, one step further from (a.len(), b.len()).
+        let mut k = -d;
+        while k <= d {
+            let idx = (k + offset as isize) as usize;
+            let mut x = if k == -d || (k != d && vb[idx - 1] < vb[idx + 1]) {
+                vb[idx + 1]
+            } else {
+                vb[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[(n - x - 1) as usize] == b[(m - y - 1) as usize] {
+                x += 1;
+                y += 1;
+            }
+            vb[idx] = x;
+
+            if !odd_delta {
+                // See the forward search above: the forward diagonal matching this
+                // backward diagonal `k` is `delta - k`.
+                let kf = delta - k;
+                if kf >= -d && kf <= d {
+                    let idxf = (kf + offset as isize) as usize;
+                    if vf[idxf] + x >= n {
+                        return Ok(((n - x) as usize, (m - y) as usize));
+                    }
+                }
+            }
+
+            k += 2;
+        }
+    }
+
+    // The searches didn't meet, so the true edit distance exceeds MAX_EDIT_DISTANCE
+    // and is too large to align practically.
+    Err(format!(
+        "--align: inputs differ too much to align within a reasonable time \
+         (edit distance exceeds {MAX_EDIT_DISTANCE}); narrow the comparison \
+         with --skip/--length and try again"
+    ))
+}
+
+/// Align a single file against the reference, returning for each reference index
+/// `0..=reference.len()` the run of bytes the file inserts just before that index,
+/// and for each reference index `0..reference.len()` whether the file is missing
+/// (gapped at) that reference byte.
+fn pairwise_alignment(reference: &[u8], other: &[u8]) -> Result<(Vec<Vec<u8>>, Vec<bool>), String> {
+    let ops = myers_diff(reference, other)?;
+
+    let mut inserts_before: Vec<Vec<u8>> = vec![Vec::new(); reference.len() + 1];
+    let mut gapped_at: Vec<bool> = vec![false; reference.len()];
+
+    let mut r = 0usize;
+    let mut o = 0usize;
+    for op in &ops {
+        match op {
+            EditOp::Equal => {
+                r += 1;
+                o += 1;
+            }
+            EditOp::Delete => {
+                gapped_at[r] = true;
+                r += 1;
+            }
+            EditOp::Insert => {
+                inserts_before[r].push(other[o]);
+                o += 1;
+            }
+        }
+    }
+
+    Ok((inserts_before, gapped_at))
+}
+
+/// A file name paired with its aligned row: one entry per reference column, `None`
+/// where this file has a gap.
+pub type AlignedRow = (String, Vec<Option<u8>>);
+
+/// Align every input file to the first ("reference") file with a Myers diff, so that
+/// matching regions line up in the same column even after byte insertions/deletions.
+///
+/// Each other file is aligned independently against the reference; wherever two
+/// files insert a different number of extra bytes at the same reference position,
+/// the narrower file (and the reference itself) is padded with gap columns so every
+/// row comes out the same length.
+///
+/// Returns an error if any pairwise alignment's edit distance exceeds
+/// [`MAX_EDIT_DISTANCE`] — see [`middle_snake`] for why that bound exists. Callers
+/// should align only the region they intend to display (see `--skip`/`--length` in
+/// [`run`]) rather than an entire multi-megabyte file.
+pub fn align_inputs(inputs: &[(String, Vec<u8>)]) -> Result<Vec<AlignedRow>, String> {
+    if inputs.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let reference = &inputs[0].1;
+    let slots = reference.len() + 1;
+
+    let mut per_file_inserts: Vec<Vec<Vec<u8>>> = Vec::with_capacity(inputs.len() - 1);
+    let mut per_file_gaps: Vec<Vec<bool>> = Vec::with_capacity(inputs.len() - 1);
+
+    for (_name, other) in &inputs[1..] {
+        let (inserts, gaps) = pairwise_alignment(reference, other)?;
+        per_file_inserts.push(inserts);
+        per_file_gaps.push(gaps);
+    }
+
+    let mut slot_width = vec![0usize; slots];
+    for inserts in &per_file_inserts {
+        for (slot, bytes) in inserts.iter().enumerate() {
+            slot_width[slot] = slot_width[slot].max(bytes.len());
+        }
+    }
+
+    let mut aligned: Vec<AlignedRow> = Vec::with_capacity(inputs.len());
+
+    // Reference row: gap-pad every insertion slot, then emit its own bytes as-is.
+    let mut ref_row: Vec<Option<u8>> = Vec::new();
+    for r in 0..reference.len() {
+        ref_row.extend(std::iter::repeat_n(None, slot_width[r]));
+        ref_row.push(Some(reference[r]));
+    }
+    ref_row.extend(std::iter::repeat_n(None, slot_width[reference.len()]));
+    aligned.push((inputs[0].0.clone(), ref_row));
+
+    for (fidx, (name, _other)) in inputs[1..].iter().enumerate() {
+        let inserts = &per_file_inserts[fidx];
+        let gaps = &per_file_gaps[fidx];
+
+        let mut row: Vec<Option<u8>> = Vec::new();
+        for r in 0..reference.len() {
+            let bytes = &inserts[r];
+            row.extend(bytes.iter().map(|b| Some(*b)));
+            row.extend(std::iter::repeat_n(None, slot_width[r] - bytes.len()));
+
+            row.push(if gaps[r] { None } else { Some(reference[r]) });
+        }
+        let trailing = &inserts[reference.len()];
+        row.extend(trailing.iter().map(|b| Some(*b)));
+        row.extend(std::iter::repeat_n(None, slot_width[reference.len()] - trailing.len()));
+
+        aligned.push((name.clone(), row));
+    }
+
+    Ok(aligned)
+}
+
+/// Render a line from pre-aligned rows (see [`align_inputs`]), slicing out columns
+/// `pos..pos + block` of each row.
+fn render_line_aligned(
+    offset: usize,
+    rows: &[AlignedRow],
+    pos: usize,
+    block: usize,
+    terse: bool,
+    format: NumberFormat,
+) -> RenderedLine {
+    let values: Vec<Vec<Option<u8>>> = rows
+        .iter()
+        .map(|(_name, row)| (0..block).map(|i| row.get(pos + i).copied().flatten()).collect())
+        .collect();
+
+    render_line_from_values(offset, &values, block, terse, format)
+}
+
 /// Render a hexdump/diff for one or more input files.
 ///
 /// When a single file is provided, produces a standard hexdump.
@@ -268,7 +793,32 @@ fn should_show_line(line: &RenderedLine, opts: &HexdiffOptions) -> bool {
 /// color-coded bytes indicating matches (green), partial matches (blue),
 /// or all-different (red).
 ///
-/// Colors are automatically disabled when stdout is not a terminal.
+/// With `opts.align` set, files are first aligned with a Myers diff against the
+/// first file (the reference) so that an inserted or deleted byte doesn't turn every
+/// subsequent position red; the `OFFSET` column then reflects the aligned position
+/// rather than each file's own raw offset.
+///
+/// `opts.format` selects the numeric radix (hex/HEX/octal/binary/decimal) used for
+/// both byte cells and the `OFFSET` column; byte classification itself is unaffected,
+/// since it compares raw values rather than their rendered representation.
+///
+/// If `opts.array` is set, skips the hex dump/diff entirely and instead prints each
+/// input as a ready-to-paste source array (`opts.terse` limits this to the first
+/// file, same as for the hex dump).
+///
+/// `opts.color` controls colorization: `Never`/`Always` force colors off/on, while
+/// `Auto` (the default) colorizes only on a terminal and also yields to the
+/// `NO_COLOR` environment variable when it's set to a non-empty value.
+///
+/// `opts.skip`/`opts.length` window the dump to a region of the file(s): the first
+/// displayed line starts at `skip` rounded down to a block boundary, and no more
+/// lines are shown once the true offset reaches `skip + length`. The `OFFSET` column
+/// (outside `opts.align` mode) always reflects true file offsets, so a windowed dump
+/// still reads like a slice of the full file rather than starting over at zero. With
+/// `opts.align` set, the window is applied *before* alignment: only the requested
+/// region of each file is aligned, not the whole thing, so a small `--skip`/`--length`
+/// window stays cheap even against large inputs. See [`align_inputs`] for the error
+/// returned when the requested region is still too large to align.
 pub fn run(
     quiet: bool,
     inputs: Vec<(String, Vec<u8>)>,
@@ -278,15 +828,35 @@ pub fn run(
         return Ok(());
     }
 
-    // Disable colors when stdout is not a terminal (e.g., piping to less/grep/file)
-    if !std::io::stdout().is_terminal() {
-        colored::control::set_override(false);
-    }
+    // `colored::control::set_override` is a process-global flag, so every branch
+    // below must set it explicitly (not just when colors should be off) — otherwise
+    // a previous `Always` call in the same process would leak into a later `Auto`
+    // call that should have disabled color.
+    let want_color = match opts.color {
+        ColorMode::Never => false,
+        ColorMode::Always => true,
+        ColorMode::Auto => {
+            // NO_COLOR (https://no-color.org/): any non-empty value disables color.
+            let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+            // Disable colors when stdout is not a terminal (e.g., piping to less/grep/file)
+            !no_color && std::io::stdout().is_terminal()
+        }
+    };
+    colored::control::set_override(want_color);
 
     if inputs.is_empty() {
         return Err("No inputs provided".to_string());
     }
 
+    if let Some(lang) = opts.array {
+        let count = if opts.terse { 1 } else { inputs.len() };
+        for (name, data) in inputs.into_iter().take(count) {
+            let window = windowed_slice(&data, opts.skip, opts.length);
+            print!("{}", render_array(&array_name(&name), window, lang));
+        }
+        return Ok(());
+    }
+
     if opts.block == 0 {
         opts.block = 16;
     }
@@ -298,17 +868,76 @@ pub fn run(
         opts.show_blue = true;
     }
 
-    let max_len = inputs.iter().map(|(_n, d)| d.len()).max().unwrap_or(0);
-    let file_names: Vec<String> = inputs.iter().map(|(n, _d)| n.clone()).collect();
-
-    print!("{}", render_header(&file_names, opts.block, opts.terse));
+    // Round the starting offset down to a block boundary so lines stay aligned, but
+    // use the unrounded skip for the end-of-window check so `--length` means what it
+    // says.
+    let window_start = (opts.skip / opts.block) * opts.block;
+
+    // Alignment only makes sense when comparing 2+ files; with a single file there's
+    // nothing to align against. Align only the requested `--skip`/`--length` window
+    // rather than the whole file: Myers alignment is O(ND) in the edit distance, so
+    // aligning an entire multi-megabyte file just to display a small window would
+    // pay the full cost of the big file for no benefit.
+    let aligned_rows = if opts.align && inputs.len() > 1 {
+        let window_length = opts.length.map(|length| length + (opts.skip - window_start));
+        let windowed: Vec<(String, Vec<u8>)> = inputs
+            .iter()
+            .map(|(name, data)| {
+                (
+                    name.clone(),
+                    windowed_slice(data, window_start, window_length).to_vec(),
+                )
+            })
+            .collect();
+        Some(align_inputs(&windowed)?)
+    } else {
+        None
+    };
+
+    let max_len = match &aligned_rows {
+        Some(rows) => rows.first().map_or(0, |(_, r)| r.len()),
+        None => inputs.iter().map(|(_n, d)| d.len()).max().unwrap_or(0),
+    };
+    let file_names: Vec<String> = match &aligned_rows {
+        Some(rows) => rows.iter().map(|(n, _r)| n.clone()).collect(),
+        None => inputs.iter().map(|(n, _d)| n.clone()).collect(),
+    };
+
+    print!(
+        "{}",
+        render_header(&file_names, opts.block, opts.terse, opts.format)
+    );
 
     let mut previous_raw: Option<String> = None;
     let mut in_repeat = false;
 
-    let mut offset = 0usize;
-    while offset < max_len {
-        let line = render_line(offset, &inputs, opts.block, opts.terse);
+    // The aligned rows above already cover only the requested window, so just walk
+    // all of them; outside align mode, `max_len` is still the full file length and
+    // needs the usual skip/length bounds applied here.
+    let (start, end) = match &aligned_rows {
+        Some(_) => (window_start, window_start.saturating_add(max_len)),
+        None => {
+            let window_end = match opts.length {
+                Some(length) => max_len.min(opts.skip.saturating_add(length)),
+                None => max_len,
+            };
+            (window_start.min(max_len), window_end)
+        }
+    };
+
+    let mut offset = start;
+    while offset < end {
+        let line = match &aligned_rows {
+            Some(rows) => render_line_aligned(
+                offset,
+                rows,
+                offset - window_start,
+                opts.block,
+                opts.terse,
+                opts.format,
+            ),
+            None => render_line(offset, &inputs, opts.block, opts.terse, opts.format),
+        };
 
         if !should_show_line(&line, &opts) {
             offset = offset.saturating_add(opts.block);
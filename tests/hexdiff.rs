@@ -1,6 +1,7 @@
 use binwalk::hexdiff::{
-    classify_block, classify_position, flags_for_classes, should_display_flags, ByteClass,
-    HexdiffOptions,
+    align_inputs, array_name, classify_block, classify_position, flags_for_classes, parse_size,
+    render_array, should_display_flags, ArrayLang, ByteClass, ColorMode, HexdiffOptions,
+    NumberFormat,
 };
 
 #[test]
@@ -72,4 +73,162 @@ fn classify_block_and_filtering() {
     assert!(should_display_flags(flags, &opts));
 }
 
+#[test]
+fn align_inputs_survives_single_byte_insertion() {
+    // f2 is f1 with one extra byte ('X') spliced in after the first byte; without
+    // alignment every position after the insertion point would look different.
+    let f1 = ("f1".to_string(), b"ABCDEF".to_vec());
+    let f2 = ("f2".to_string(), b"AXBCDEF".to_vec());
+
+    let aligned = align_inputs(&[f1, f2]).expect("inputs are well within the alignment bound");
+    assert_eq!(aligned.len(), 2);
+    assert_eq!(aligned[0].1.len(), aligned[1].1.len());
+
+    // The inserted 'X' should show up as a gap in the reference row...
+    let insert_pos = aligned[1]
+        .1
+        .iter()
+        .position(|b| *b == Some(b'X'))
+        .expect("inserted byte should be present");
+    assert_eq!(aligned[0].1[insert_pos], None);
+
+    // ...and every other position should line up and match exactly.
+    for (i, (ref_b, other_b)) in aligned[0].1.iter().zip(aligned[1].1.iter()).enumerate() {
+        if i != insert_pos {
+            assert_eq!(ref_b, other_b, "mismatch at aligned column {i}");
+        }
+    }
+}
+
+#[test]
+fn align_inputs_identical_files_have_no_gaps() {
+    let f1 = ("f1".to_string(), b"ABCD".to_vec());
+    let f2 = ("f2".to_string(), b"ABCD".to_vec());
+
+    let aligned = align_inputs(&[f1, f2]).expect("inputs are well within the alignment bound");
+    assert_eq!(aligned[0].1, vec![Some(b'A'), Some(b'B'), Some(b'C'), Some(b'D')]);
+    assert_eq!(aligned[0].1, aligned[1].1);
+}
+
+#[test]
+fn align_inputs_rejects_pathologically_dissimilar_inputs() {
+    // Two inputs with no bytes in common at all drive the Myers edit distance up to
+    // N+M; past a point that's no longer practical to align, and align_inputs should
+    // say so instead of taking a very long time.
+    let f1 = ("f1".to_string(), vec![0u8; 8000]);
+    let f2 = ("f2".to_string(), vec![1u8; 8000]);
+
+    let err = align_inputs(&[f1, f2]).expect_err("wildly dissimilar inputs should be rejected");
+    assert!(err.contains("--align"));
+}
+
+#[test]
+fn align_inputs_handles_large_inputs_with_few_scattered_edits() {
+    // A large buffer whose true edit distance is tiny (a handful of scattered
+    // single-byte insertions) should align cheaply: the cost tracks the edit
+    // distance, not the input size.
+    let base = vec![0x42u8; 50_000];
+    let mut edited = base.clone();
+    for pos in [1000, 20_000, 49_000] {
+        edited.insert(pos, 0xFF);
+    }
+
+    let f1 = ("f1".to_string(), base);
+    let f2 = ("f2".to_string(), edited);
+
+    let aligned = align_inputs(&[f1, f2]).expect("small true edit distance should align");
+    assert_eq!(aligned[0].1.len(), aligned[1].1.len());
+
+    let gaps = aligned[0].1.iter().filter(|b| b.is_none()).count();
+    assert_eq!(gaps, 3);
+}
+
+#[test]
+fn number_format_parse_accepts_known_names() {
+    assert_eq!(NumberFormat::parse("hex"), Ok(NumberFormat::Hex));
+    assert_eq!(NumberFormat::parse("HEX"), Ok(NumberFormat::HexUpper));
+    assert_eq!(NumberFormat::parse("octal"), Ok(NumberFormat::Octal));
+    assert_eq!(NumberFormat::parse("binary"), Ok(NumberFormat::Binary));
+    assert_eq!(NumberFormat::parse("decimal"), Ok(NumberFormat::Decimal));
+    assert!(NumberFormat::parse("hexadecimal").is_err());
+}
+
+#[test]
+fn number_format_renders_fixed_width_cells() {
+    assert_eq!(NumberFormat::Hex.render_byte(0x4a), "4a");
+    assert_eq!(NumberFormat::HexUpper.render_byte(0x4a), "4A");
+    assert_eq!(NumberFormat::Octal.render_byte(0o112), "112");
+    assert_eq!(NumberFormat::Binary.render_byte(0b0100_1010), "01001010");
+    assert_eq!(NumberFormat::Decimal.render_byte(74), "074");
+
+    // Gap placeholders match the width of a rendered byte in the same radix.
+    for format in [
+        NumberFormat::Hex,
+        NumberFormat::HexUpper,
+        NumberFormat::Octal,
+        NumberFormat::Binary,
+        NumberFormat::Decimal,
+    ] {
+        assert_eq!(format.gap_placeholder().len(), format.cell_width());
+    }
+}
+
+#[test]
+fn array_name_sanitizes_file_names() {
+    assert_eq!(array_name("firmware.bin"), "firmware");
+    assert_eq!(array_name("/tmp/dump-1.raw"), "dump_1");
+    assert_eq!(array_name("123.bin"), "_123");
+}
+
+#[test]
+fn render_array_in_each_language() {
+    let data = [0x41u8, 0x42];
+
+    assert_eq!(
+        render_array("data", &data, ArrayLang::C),
+        "unsigned char data[] = { 0x41, 0x42 };\n"
+    );
+    assert_eq!(
+        render_array("data", &data, ArrayLang::Rust),
+        "let data: [u8; 2] = [0x41, 0x42];\n"
+    );
+    assert_eq!(
+        render_array("data", &data, ArrayLang::Python),
+        "data = bytes([0x41, 0x42])\n"
+    );
+    assert_eq!(render_array("data", &data, ArrayLang::HexString), "data = 4142\n");
+}
+
+#[test]
+fn array_lang_parse_rejects_unknown() {
+    assert_eq!(ArrayLang::parse("c"), Ok(ArrayLang::C));
+    assert!(ArrayLang::parse("java").is_err());
+}
+
+#[test]
+fn color_mode_parse_accepts_known_names() {
+    assert_eq!(ColorMode::parse("never"), Ok(ColorMode::Never));
+    assert_eq!(ColorMode::parse("always"), Ok(ColorMode::Always));
+    assert_eq!(ColorMode::parse("auto"), Ok(ColorMode::Auto));
+    assert!(ColorMode::parse("sometimes").is_err());
+    assert_eq!(ColorMode::default(), ColorMode::Auto);
+}
+
+#[test]
+fn parse_size_accepts_hex_and_suffixes() {
+    assert_eq!(parse_size("256"), Ok(256));
+    assert_eq!(parse_size("0x100"), Ok(256));
+    assert_eq!(parse_size("0X100"), Ok(256));
+    assert_eq!(parse_size("4K"), Ok(4 * 1024));
+    assert_eq!(parse_size("1M"), Ok(1024 * 1024));
+    assert_eq!(parse_size("1g"), Ok(1024 * 1024 * 1024));
+    assert!(parse_size("nope").is_err());
+}
+
+#[test]
+fn parse_size_rejects_overflow() {
+    assert!(parse_size("18446744073709551615K").is_err());
+    assert!(parse_size("18446744073709551615G").is_err());
+}
+
 